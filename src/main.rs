@@ -21,6 +21,9 @@ fn main() {
     struct SomeStruct {}
 
     // Then, inside curly brackets, we define the names and types of the pieces of data, which we call fields.
+    // We derive Default so there is a sensible base instance to build from (empty strings, the
+    // numeric/boolean fields at their zero values); the UserBuilder below relies on it.
+    #[derive(Default)]
     struct User {
         username: String,
         email: String,
@@ -96,6 +99,44 @@ fn main() {
         ..user1
     };
 
+    // build_user always restates active: true and sign_in_count: 1. When a caller only cares about
+    // a couple of fields, a builder is friendlier: start from the Default base and layer on just the
+    // fields you want with fluent setters, letting struct update syntax fill in the rest.
+    #[derive(Default)]
+    struct UserBuilder {
+        email: String,
+        username: String,
+    }
+
+    impl UserBuilder {
+        // Each setter takes `self` by value and returns it, so calls can be chained.
+        fn email(mut self, email: String) -> UserBuilder {
+            self.email = email;
+            self
+        }
+
+        fn username(mut self, username: String) -> UserBuilder {
+            self.username = username;
+            self
+        }
+
+        // `..User::default()` is the same struct update syntax used above, here spreading the
+        // default instance so we only have to name the fields the builder actually collected.
+        fn build(self) -> User {
+            User {
+                email: self.email,
+                username: self.username,
+                ..User::default()
+            }
+        }
+    }
+
+    let built = UserBuilder::default()
+        .email(String::from("builder@example.com"))
+        .username(String::from("builderuser"))
+        .build();
+    println!("Username: {}", built.username);
+
     // Using Tuple Structs without Named Fields to Create Different Types ---
     // Tuple structs have the added meaning the struct name provides but don’t have names associated with their fields;
     // rather, they just have the types of the fields.
@@ -133,4 +174,57 @@ fn main() {
         sign_in_count: u64,
         active: bool,
     }
+
+    // Here is the version that actually compiles. The `<'a>` after the name introduces a lifetime
+    // parameter, and writing the fields as `&'a str` tells the compiler that a UserRef may not
+    // outlive the string data it borrows. This is the borrowing alternative to the owned User:
+    // it stores no Strings of its own, so constructing one copies nothing.
+    struct UserRef<'a> {
+        username: &'a str,
+        email: &'a str,
+        sign_in_count: u64,
+        active: bool,
+    }
+
+    // A constructor mirroring build_user above, but taking string slices instead of owned Strings.
+    impl<'a> UserRef<'a> {
+        fn new(email: &'a str, username: &'a str) -> UserRef<'a> {
+            UserRef {
+                email,
+                username,
+                active: true,
+                sign_in_count: 1,
+            }
+        }
+
+        // Turning a borrowed UserRef back into an owned User copies the slices into new Strings,
+        // giving us a value that owns its data and no longer depends on the borrowed source.
+        fn to_owned(&self) -> User {
+            User {
+                email: String::from(self.email),
+                username: String::from(self.username),
+                active: self.active,
+                sign_in_count: self.sign_in_count,
+            }
+        }
+    }
+
+    // Going the other way, an owned User can hand out a zero-copy UserRef that borrows its fields;
+    // the returned UserRef borrows `self`, so it can't outlive the User it came from.
+    impl User {
+        fn as_ref(&self) -> UserRef {
+            UserRef {
+                email: &self.email,
+                username: &self.username,
+                active: self.active,
+                sign_in_count: self.sign_in_count,
+            }
+        }
+    }
+
+    let borrowed = UserRef::new("someone@example.com", "someusername123");
+    println!("Username: {}", borrowed.username);
+    let owned_again = borrowed.to_owned();
+    let back_to_ref = owned_again.as_ref();
+    println!("Username: {}", back_to_ref.username);
 }
@@ -9,18 +9,91 @@
 // Let’s change the area function that has a Rectangle (from the previous example) instance as a parameter and instead
 // make an area method defined on the Rectangle struct.
 
+// Hard-coding u32 ties Rectangle to whole, non-negative pixels. Real callers want fractional
+// pixels (f64) or signed coordinates (i64) too. We make Rectangle generic over the element type T
+// and constrain it only by what the methods actually need:
+//   - Copy so we can read width/height twice without moving them,
+//   - Mul so area can multiply them (area then returns T, whatever T is),
+//   - PartialOrd so can_hold can compare dimensions.
+// The method signatures stay the same shape; only the concrete type `u32` becomes the parameter T.
+use std::ops::Mul;
+
+// A plain `T` for both fields means nothing stops a caller from passing the height where the width
+// goes—they are the same type, so the compiler can't help. We borrow the tuple-struct idea
+// (like `Color(i32, i32, i32)`) and give each dimension its own named type. Now `Width` and
+// `Height` are different types, so `Rectangle { width: Height(50), .. }` simply won't compile.
 #[derive(Debug)]
-struct Rectangle {
-    width: u32,
-    height: u32,
+struct Width<T>(T);
+
+#[derive(Debug)]
+struct Height<T>(T);
+
+// `From<T>` keeps construction ergonomic: callers can write `Width::from(30)` or rely on `.into()`,
+// staying close to the old bare-`u32` feel while still getting the type safety.
+impl<T> From<T> for Width<T> {
+    fn from(value: T) -> Self {
+        Width(value)
+    }
+}
+
+impl<T> From<T> for Height<T> {
+    fn from(value: T) -> Self {
+        Height(value)
+    }
+}
+
+// Accessor methods let the rest of the code read the wrapped value without reaching into `.0`.
+impl<T: Copy> Width<T> {
+    fn get(&self) -> T {
+        self.0
+    }
+}
+
+impl<T: Copy> Height<T> {
+    fn get(&self) -> T {
+        self.0
+    }
 }
 
+#[derive(Debug)]
+struct Rectangle<T> {
+    width: Width<T>,
+    height: Height<T>,
+}
+
+// Deriving Debug gives us developer-facing output, but Debug is not meant for end users.
+// When we want to present a Rectangle to a person—in a log line, a CLI message, a UI label—we
+// implement std::fmt::Display by hand so we control exactly what the user reads.
+// Unlike Debug, there is no #[derive] for Display: there is no single obvious way to show a type
+// to a user, so Rust makes us spell it out.
+impl<T: std::fmt::Display + Copy + Mul<Output = T>> std::fmt::Display for Rectangle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        // The formatter remembers whether the caller used the alternate flag `{:#}`.
+        // We branch on it so `{}` stays on a single line while `{:#}` prints a labeled layout.
+        if f.alternate() {
+            write!(
+                f,
+                "Rectangle\n  width:  {} px\n  height: {} px\n  area:   {} px\u{b2}",
+                self.width.get(),
+                self.height.get(),
+                self.area()
+            )
+        } else {
+            write!(f, "{}\u{d7}{} rectangle, area {} px\u{b2}", self.width.get(), self.height.get(), self.area())
+        }
+    }
+}
+
+// With Display in place, `println!("rect1 is {}", rect1)` now compiles and prints
+// `30×50 rectangle, area 1500 px²`, and `{:#}` prints the multi-line labeled version—both
+// distinct from the `Rectangle { width: Width(30), height: Height(50) }` that Debug produces.
+
 // To define the function within the context of Rectangle, we start an impl (implementation) block.
 // Then we move the area function within the impl curly brackets and change the first (and in this case, only) parameter
 // to be self in the signature and everywhere within the body.
-impl Rectangle {
-    fn area(&self) -> u32 {
-        self.width * self.height
+impl<T: Copy + Mul<Output = T>> Rectangle<T> {
+    fn area(&self) -> T {
+        self.width.get() * self.height.get()
     }
 }
 
@@ -29,8 +102,8 @@ impl Rectangle {
 // parentheses, and any arguments.
 fn main() {
     let rect1 = Rectangle {
-        width: 30,
-        height: 50,
+        width: Width(30),
+        height: Height(50),
     };
 
     println!(
@@ -86,16 +159,16 @@ fn main() {
 // the second Rectangle can fit completely within self; otherwise it should return false.
 fn main_2() {
     let rect1 = Rectangle {
-        width: 30,
-        height: 50,
+        width: Width(30),
+        height: Height(50),
     };
     let rect2 = Rectangle {
-        width: 10,
-        height: 40,
+        width: Width(10),
+        height: Height(40),
     };
     let rect3 = Rectangle {
-        width: 60,
-        height: 45,
+        width: Width(60),
+        height: Height(45),
     };
 
     println!("Can rect1 hold rect2? {}", rect1.can_hold(&rect2)); // true
@@ -110,13 +183,13 @@ fn main_2() {
 // This makes sense because we only need to read rect2 (rather than write, which would mean we’d need a mutable borrow),
 // and we want main to retain ownership of rect2 so we can use it again after calling the can_hold method.
 
-impl Rectangle {
-    fn area(&self) -> u32 {
-        self.width * self.height
+impl<T: Copy + Mul<Output = T> + PartialOrd> Rectangle<T> {
+    fn area(&self) -> T {
+        self.width.get() * self.height.get()
     }
 
-    fn can_hold(&self, other: &Rectangle) -> bool {
-        self.width > other.width && self.height > other.height
+    fn can_hold(&self, other: &Rectangle<T>) -> bool {
+        self.width.get() > other.width.get() && self.height.get() > other.height.get()
     }
 }
 
@@ -136,11 +209,11 @@ impl Rectangle {
 // that as both width and height, thus making it easier to create a square Rectangle
 // rather than having to specify the same value twice:
 
-impl Rectangle {
-    fn square(size: u32) -> Rectangle {
+impl<T: Copy> Rectangle<T> {
+    fn square(size: T) -> Rectangle<T> {
         Rectangle {
-            width: size,
-            height: size,
+            width: Width(size),
+            height: Height(size),
         }
     }
 }
@@ -153,16 +226,117 @@ fn asd() {
 // Multiple impl Blocks ---
 // Each struct is allowed to have multiple impl blocks.
 
-impl Rectangle {
-    fn area(&self) -> u32 {
-        self.width * self.height
+impl<T: Copy + Mul<Output = T>> Rectangle<T> {
+    fn area(&self) -> T {
+        self.width.get() * self.height.get()
+    }
+}
+
+impl<T: Copy + PartialOrd> Rectangle<T> {
+    fn can_hold(&self, other: &Rectangle<T>) -> bool {
+        self.width.get() > other.width.get() && self.height.get() > other.height.get()
+    }
+}
+
+// NOTE: there’s no reason to separate these methods into multiple impl blocks here, but this is valid syntax.
+
+// Traits: Shared Behavior Across Shapes ---------------------------------------------------------
+
+// So far `area` and `can_hold` live only on Rectangle. But a square or a circle has an area too,
+// and we'd like to treat any of them uniformly—for example, to sum the area of a whole drawing.
+// A trait captures that shared behavior: any type that implements Shape promises to report its
+// area and perimeter, and in return gets a `contains` check for free.
+use std::f64::consts::PI;
+
+trait Shape {
+    fn area(&self) -> f64;
+    fn perimeter(&self) -> f64;
+
+    // Every shape can report the width and height of its axis-aligned bounding box. We phrase the
+    // default `contains` in terms of those boxes so it works for any pair of shapes.
+    fn bounding_box(&self) -> (f64, f64);
+
+    // A default method: implementors inherit this unless they choose to override it. One shape
+    // "contains" another when its bounding box is at least as large in both dimensions.
+    // The `other: &impl Shape` parameter is generic, so we add `where Self: Sized` to keep Shape
+    // object-safe (it must be, since we store `Box<dyn Shape>` below).
+    fn contains(&self, other: &impl Shape) -> bool
+    where
+        Self: Sized,
+    {
+        let (width, height) = self.bounding_box();
+        let (other_width, other_height) = other.bounding_box();
+        width >= other_width && height >= other_height
     }
 }
 
-impl Rectangle {
-    fn can_hold(&self, other: &Rectangle) -> bool {
-        self.width > other.width && self.height > other.height
+// Rectangle already knew its dimensions; now it advertises them through Shape. We only require the
+// element type to be convertible into f64, so u32 and f64 rectangles both qualify.
+impl<T: Into<f64> + Copy> Shape for Rectangle<T> {
+    fn area(&self) -> f64 {
+        self.width.get().into() * self.height.get().into()
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * (self.width.get().into() + self.height.get().into())
     }
+
+    fn bounding_box(&self) -> (f64, f64) {
+        (self.width.get().into(), self.height.get().into())
+    }
+}
+
+// A Square is a newtype over its side length—another use of the tuple-struct pattern.
+struct Square(f64);
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+
+    fn perimeter(&self) -> f64 {
+        4.0 * self.0
+    }
+
+    fn bounding_box(&self) -> (f64, f64) {
+        (self.0, self.0)
+    }
+}
+
+// A Circle stores its radius; its bounding box is the surrounding square of side 2r.
+struct Circle {
+    radius: f64,
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        PI * self.radius * self.radius
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * PI * self.radius
+    }
+
+    fn bounding_box(&self) -> (f64, f64) {
+        (2.0 * self.radius, 2.0 * self.radius)
+    }
+}
+
+// Because Shape is object-safe, we can erase the concrete types into trait objects and keep a mixed
+// collection of shapes, then compute totals by calling through the trait.
+fn total_area(shapes: &[Box<dyn Shape>]) -> f64 {
+    shapes.iter().map(|shape| shape.area()).sum()
 }
 
-// NOTE: there’s no reason to separate these methods into multiple impl blocks here, but this is valid syntax.
\ No newline at end of file
+fn shapes_demo() {
+    let shapes: Vec<Box<dyn Shape>> = vec![
+        Box::new(Rectangle {
+            width: Width(30u32),
+            height: Height(50u32),
+        }),
+        Box::new(Square(10.0)),
+        Box::new(Circle { radius: 2.0 }),
+    ];
+
+    println!("Total area of all shapes: {}", total_area(&shapes));
+}
\ No newline at end of file